@@ -0,0 +1,39 @@
+#![allow(clippy::reversed_empty_ranges)]
+
+use std::hint::black_box;
+use std::ops::Bound;
+
+use clip_slice::{clip, Clip, ClipBounds, ClipSlice};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+#[path = "../src/inlined.rs"]
+mod inlined;
+
+use inlined::inlined_by;
+
+fn bench_clip(c: &mut Criterion) {
+    let v: Vec<u8> = (0..1 << 20).map(|i| i as u8).collect();
+
+    macro_rules! bench_range {
+        ( $name:expr, $range:expr ) => {
+            let mut group = c.benchmark_group($name);
+            group.bench_function("inlined", |b| {
+                b.iter(|| inlined_by(black_box(&v[..]), black_box($range)).len())
+            });
+            group.bench_function("Clip::by", |b| {
+                b.iter(|| Clip::by(black_box(&v[..]), black_box($range)).len())
+            });
+            group.finish();
+        };
+    }
+
+    bench_range!("range", 100..-100);
+    bench_range!("range_from", -100..);
+    bench_range!("range_to", ..-100);
+    bench_range!("range_inclusive", 100..=-100);
+    bench_range!("range_to_inclusive", ..=-100);
+    bench_range!("range_full", ..);
+}
+
+criterion_group!(benches, bench_clip);
+criterion_main!(benches);
@@ -1,10 +1,16 @@
+#![allow(
+    clippy::map_clone,
+    clippy::needless_borrow,
+    clippy::reversed_empty_ranges
+)]
+
 use clip_slice::{Clip, ClipAsSlice, ClipSlice};
 
 fn main() {
     // generating slices with negative indices.
     let a = [0, 1, 2, 3, 4, 5];
-    assert_eq!(Clip::by(&a[..], ..-2), &[0, 1, 2, 3]);
-    assert_eq!(Clip::by(&a[..], -4..-1), &[2, 3, 4]);
+    assert_eq!(Clip::by(&a, ..-2), &[0, 1, 2, 3]);
+    assert_eq!(Clip::by(&a, -4..-1), &[2, 3, 4]);
 
     // use in combination with rev and step_by
     let a = [0, 1, 2, 3, 4, 5];
@@ -23,27 +29,27 @@ fn main() {
     ); // normal slice & rev, shortened with a macro
 
     assert_eq!(
-        ref_iter_to_vec!(Clip::by(&a[..], ..-2).iter().rev(); isize),
+        ref_iter_to_vec!(Clip::by(&a, ..-2).iter().rev(); isize),
         vec![3, 2, 1, 0]
     ); // clipped slice & rev
     assert_eq!(
-        ref_iter_to_vec!(Clip::by(&a[..], ..-2).iter().rev().step_by(2); isize),
+        ref_iter_to_vec!(Clip::by(&a, ..-2).iter().rev().step_by(2); isize),
         vec![3, 1]
     ); // clipped slice & rev/step_by
 
     // generating mutable slices
     let mut a = [0, 1, 2, 3, 4, 5];
-    let s = Clip::mut_by(&mut a[..], 1..-2);
+    let s = Clip::mut_by(&mut a, 1..-2);
     s[0] = 10;
     assert_eq!(a, [0, 10, 2, 3, 4, 5]);
 
     // accessing items with negative indices
     let a = [0, 1, 2, 3, 4, 5];
-    assert_eq!(Clip::by(&a[..], -1..)[0], 5);
-    assert_eq!(Clip::by(&a[..], -2..)[0], 4);
+    assert_eq!(Clip::by(&a, -1..)[0], 5);
+    assert_eq!(Clip::by(&a, -2..)[0], 4);
     macro_rules! at {
         ( $slice:expr , $index:expr ) => {
-            Clip::by(&$slice[..], $index..)[0]
+            Clip::by(&$slice, $index..)[0]
         };
     }
     assert_eq!(at!(a, -1), 5); // shortened with a macro
@@ -56,7 +62,7 @@ fn main() {
 
     macro_rules! mut_ref_at {
         ( $slice:expr , $index:expr ) => {
-            &mut Clip::mut_by(&mut $slice[..], $index..)[0]
+            &mut Clip::mut_by(&mut $slice, $index..)[0]
         };
     }
     let mut a = [0, 1, 2, 3, 4, 5];
@@ -0,0 +1,55 @@
+#![allow(clippy::reversed_empty_ranges, clippy::useless_vec)]
+
+use clip_slice::clip;
+
+fn main() {
+    // generating slices with negative indices.
+    let a = [0, 1, 2, 3, 4, 5];
+    assert_eq!(clip!(a, ..-2), &[0, 1, 2, 3]);
+    assert_eq!(clip!(a, -4..-1), &[2, 3, 4]);
+
+    // use in combination with rev and step_by
+    let a = [0, 1, 2, 3, 4, 5];
+    macro_rules! ref_iter_to_vec {
+        ( $e:expr ; $t:ty ) => {
+            $e.map(|&n| n).collect::<Vec<$t>>()
+        };
+    }
+    assert_eq!(
+        ref_iter_to_vec!(clip!(a, ..-2).iter().rev(); isize),
+        vec![3, 2, 1, 0]
+    ); // clipped slice & rev
+    assert_eq!(
+        ref_iter_to_vec!(clip!(a, ..-2).iter().rev().step_by(2); isize),
+        vec![3, 1]
+    ); // clipped slice & rev/step_by
+
+    // generating mutable slices
+    let mut a = [0, 1, 2, 3, 4, 5];
+    let s = clip!(mut a, 1..-2);
+    s[0] = 10;
+    assert_eq!(a, [0, 10, 2, 3, 4, 5]);
+
+    // accessing items with negative indices
+    let a = [0, 1, 2, 3, 4, 5];
+    assert_eq!(*clip!(a[-1]), 5);
+    assert_eq!(*clip!(a[-2]), 4);
+
+    // assign items with negative index
+    let mut a = [0, 1, 2, 3, 4, 5];
+    *clip!(mut a[-1]) = 50;
+    assert_eq!(a, [0, 1, 2, 3, 4, 50]);
+
+    let mut v = vec![0, 1, 2, 3, 4, 5];
+    *clip!(mut v[-2]) = 40;
+    assert_eq!(v, vec![0, 1, 2, 3, 40, 5]);
+
+    // generating slices from vectors
+    let v = vec![0, 1, 2, 3, 4, 5];
+    assert_eq!(clip!(v, ..-2), &[0, 1, 2, 3]);
+
+    let mut v = vec![0, 1, 2, 3, 4, 5];
+    let s = clip!(mut v, 1..-2);
+    s[0] = 10;
+    assert_eq!(v, vec![0, 10, 2, 3, 4, 5]);
+}
@@ -0,0 +1,35 @@
+// The range-to-offset logic as it was before being factored out of the generic code,
+// i.e., inlined into every instantiation for each type of item and range.
+// The benchmark compares `Clip::by` with it, and the tests check that both give the same results as an independent oracle.
+// It is included by both, so it only names items that are in scope in the crate root of each.
+
+use super::{clip, Bound, ClipBounds};
+
+// Resolves a range against a length into the start and end positions, in the same way as `Clip::resolve`.
+#[inline(always)]
+pub fn inlined_range<R: ClipBounds>(len: usize, range: &R) -> (usize, usize) {
+    let clip_inclusive = |pos: isize| {
+        if pos < 0 && pos.unsigned_abs() > len {
+            0
+        } else {
+            (clip(pos, len) + 1).min(len)
+        }
+    };
+    let start = match range.clip_start_bound() {
+        Bound::Included(pos) => clip(pos, len),
+        Bound::Excluded(pos) => clip_inclusive(pos),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.clip_end_bound() {
+        Bound::Included(pos) => clip_inclusive(pos),
+        Bound::Excluded(pos) => clip(pos, len),
+        Bound::Unbounded => len,
+    };
+    (start, end)
+}
+
+// Same as `Clip::by`.
+pub fn inlined_by<T, R: ClipBounds>(slice: &[T], range: R) -> &[T] {
+    let (start, end) = inlined_range(slice.len(), &range);
+    &slice[start..end]
+}
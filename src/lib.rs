@@ -1,15 +1,30 @@
 #![doc = include_str!("../README.md")]
+#![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
+#[cfg(any(feature = "std", test))]
+extern crate std;
 
-fn clip(pos: isize, len: usize) -> usize {
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{
+    Bound, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+use core::slice::{Chunks, Windows};
+
+/// Clips a position possibly a negative index against a length of a slice.
+/// A negative position is interpreted as going backwards from the back end, and the result is clamped to `0..=len`.
+/// So `clip(-2, 4)` is `2`, `clip(-10, 4)` is `0`, and `clip(10, 4)` is `4`.
+/// As a `const fn`, it can also be used to compute offsets in a constant context.
+pub const fn clip(pos: isize, len: usize) -> usize {
     if pos < 0 {
-        let neg_pos = (-pos) as usize;
-        if neg_pos >= len {
-            0
-        } else {
-            len - neg_pos
-        }
+        len.saturating_sub(pos.unsigned_abs())
     } else if pos as usize > len {
         len
     } else {
@@ -17,161 +32,1072 @@ fn clip(pos: isize, len: usize) -> usize {
     }
 }
 
-/// A struct that serves as a prefix for the functions `by`, `mut_by`, `by_as_slice`, and `by_as_mut_slice`.
+// Same as `clip`, but reports a position clamped to `0` or `len` through the `log` crate,
+// when the `trace` feature is enabled. `clip` itself stays a plain `const fn`.
+fn clip_traced(pos: isize, len: usize) -> usize {
+    let clipped = clip(pos, len);
+    #[cfg(feature = "trace")]
+    trace_clamp(
+        pos,
+        len,
+        clipped,
+        pos < 0 && pos.unsigned_abs() > len,
+        pos > 0 && pos as usize > len,
+    );
+    clipped
+}
+
+// Converts an inclusive end position into an exclusive one, after clipping.
+// A position before the front end gives `0`, so that it does not include the first item.
+// With the `trace` feature, the exclusive position is reported when it is clamped to `0` or `len`.
+fn clip_inclusive(pos: isize, len: usize) -> usize {
+    let end = if pos < 0 && pos.unsigned_abs() > len {
+        0
+    } else {
+        (clip(pos, len) + 1).min(len)
+    };
+    #[cfg(feature = "trace")]
+    trace_clamp(
+        pos,
+        len,
+        end,
+        pos < 0 && pos.unsigned_abs() - 1 > len,
+        pos >= 0 && pos as usize >= len,
+    );
+    end
+}
+
+// Clamps a number of items to `len`, reporting it in the same way as `clip_traced`.
+fn clip_count(n: usize, len: usize) -> usize {
+    #[cfg(feature = "trace")]
+    if n > len {
+        log::trace!("clip: n={} len={} -> {} (overflow)", n, len, len);
+    }
+    n.min(len)
+}
+
+// Logs that a position has been clamped to the front or back end, with the position actually used.
+#[cfg(feature = "trace")]
+fn trace_clamp(pos: isize, len: usize, used: usize, underflow: bool, overflow: bool) {
+    if underflow {
+        log::trace!("clip: pos={} len={} -> {} (underflow)", pos, len, used);
+    } else if overflow {
+        log::trace!("clip: pos={} len={} -> {} (overflow)", pos, len, used);
+    }
+}
+
+// Same as `clip`, but returns `None` instead of clamping a position outside `-len..=len`.
+fn try_clip(pos: isize, len: usize) -> Option<usize> {
+    if pos < 0 {
+        len.checked_sub(pos.unsigned_abs())
+    } else if pos as usize > len {
+        None
+    } else {
+        Some(pos as usize)
+    }
+}
+
+// Same as `clip_inclusive`, but returns `None` instead of clamping a position whose exclusive equivalent
+// is outside `-len..=len`, i.e., an inclusive position outside `-(len + 1)..=len - 1`.
+fn try_clip_inclusive(pos: isize, len: usize) -> Option<usize> {
+    if pos == -1 {
+        Some(len)
+    } else {
+        try_clip(pos.checked_add(1)?, len)
+    }
+}
+
+// Resolves a range against a length into the start and end positions of the clipped slice.
+fn clip_range<R: ClipBounds>(range: &R, len: usize) -> (usize, usize) {
+    resolve_bounds(len, range.clip_start_bound(), range.clip_end_bound())
+}
+
+// The non-generic part of `clip_range`. It is kept apart so that it is compiled once,
+// rather than inlined into `by` and the others for every type of item and range.
+fn resolve_bounds(len: usize, start: Bound<isize>, end: Bound<isize>) -> (usize, usize) {
+    let start = match start {
+        Bound::Included(pos) => clip_traced(pos, len),
+        Bound::Excluded(pos) => clip_inclusive(pos, len),
+        Bound::Unbounded => 0,
+    };
+    let end = match end {
+        Bound::Included(pos) => clip_inclusive(pos, len),
+        Bound::Excluded(pos) => clip_traced(pos, len),
+        Bound::Unbounded => len,
+    };
+    (start, end)
+}
+
+// Same as `clip_range`, but returns `None` when a position is outside `-len..=len` or the start comes after the end.
+fn try_clip_range<R: ClipBounds>(range: &R, len: usize) -> Option<(usize, usize)> {
+    try_resolve_bounds(len, range.clip_start_bound(), range.clip_end_bound())
+}
+
+// The non-generic part of `try_clip_range`.
+fn try_resolve_bounds(
+    len: usize,
+    start: Bound<isize>,
+    end: Bound<isize>,
+) -> Option<(usize, usize)> {
+    let start = match start {
+        Bound::Included(pos) => try_clip(pos, len)?,
+        Bound::Excluded(pos) => try_clip_inclusive(pos, len)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match end {
+        Bound::Included(pos) => try_clip_inclusive(pos, len)?,
+        Bound::Excluded(pos) => try_clip(pos, len)?,
+        Bound::Unbounded => len,
+    };
+    if start > end {
+        None
+    } else {
+        Some((start, end))
+    }
+}
+
+// Same as `clip`, but panics when a position is outside `-len..=len`.
+fn strict_clip(pos: isize, len: usize) -> usize {
+    match try_clip(pos, len) {
+        Some(p) => p,
+        None => panic!("clip_slice: index {} out of range for length {}", pos, len),
+    }
+}
+
+// Same as `clip_inclusive`, but panics when the exclusive equivalent of a position is outside `-len..=len`.
+fn strict_clip_inclusive(pos: isize, len: usize) -> usize {
+    match try_clip_inclusive(pos, len) {
+        Some(p) => p,
+        None => panic!("clip_slice: index {} out of range for length {}", pos, len),
+    }
+}
+
+// Same as `clip_range`, but panics when a position is outside `-len..=len` or the start comes after the end.
+fn strict_clip_range<R: ClipBounds>(range: &R, len: usize) -> (usize, usize) {
+    strict_resolve_bounds(len, range.clip_start_bound(), range.clip_end_bound())
+}
+
+// The non-generic part of `strict_clip_range`.
+fn strict_resolve_bounds(len: usize, start: Bound<isize>, end: Bound<isize>) -> (usize, usize) {
+    let start = match start {
+        Bound::Included(pos) => strict_clip(pos, len),
+        Bound::Excluded(pos) => strict_clip_inclusive(pos, len),
+        Bound::Unbounded => 0,
+    };
+    let end = match end {
+        Bound::Included(pos) => strict_clip_inclusive(pos, len),
+        Bound::Excluded(pos) => strict_clip(pos, len),
+        Bound::Unbounded => len,
+    };
+    if start > end {
+        panic!(
+            "clip_slice: range start {} is greater than end {} for length {}",
+            start, end, len
+        );
+    }
+    (start, end)
+}
+
+// Converts an index of an item possibly negative into a position, without checking the upper bound.
+fn item_index(index: isize, len: usize) -> Option<usize> {
+    if index < 0 {
+        len.checked_sub(index.unsigned_abs())
+    } else {
+        Some(index as usize)
+    }
+}
+
+// Same as `clip`, but returns `None` instead of clamping a negative position before the front end.
+fn get_clip(pos: isize, len: usize) -> Option<usize> {
+    item_index(pos, len).map(|_| clip_traced(pos, len))
+}
+
+// Same as `clip_inclusive`, but returns `None` instead of clamping a negative position whose exclusive equivalent
+// is before the front end, i.e., an inclusive position before `-(len + 1)`.
+fn get_clip_inclusive(pos: isize, len: usize) -> Option<usize> {
+    if pos < 0 && pos.unsigned_abs() - 1 > len {
+        None
+    } else {
+        Some(clip_inclusive(pos, len))
+    }
+}
+
+// Same as `clip_range`, but returns `None` when a negative position is before the front end or the start comes after the end.
+fn get_clip_range<R: ClipBounds>(range: &R, len: usize) -> Option<(usize, usize)> {
+    get_resolve_bounds(len, range.clip_start_bound(), range.clip_end_bound())
+}
+
+// The non-generic part of `get_clip_range`.
+fn get_resolve_bounds(
+    len: usize,
+    start: Bound<isize>,
+    end: Bound<isize>,
+) -> Option<(usize, usize)> {
+    let start = match start {
+        Bound::Included(pos) => get_clip(pos, len)?,
+        Bound::Excluded(pos) => get_clip_inclusive(pos, len)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match end {
+        Bound::Included(pos) => get_clip_inclusive(pos, len)?,
+        Bound::Excluded(pos) => get_clip(pos, len)?,
+        Bound::Unbounded => len,
+    };
+    if start > end {
+        None
+    } else {
+        Some((start, end))
+    }
+}
+
+// Converts a character offset into a byte offset of the string.
+fn char_to_byte(s: &str, n: usize) -> usize {
+    s.char_indices().nth(n).map_or(s.len(), |(i, _)| i)
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A trait for the integer types that can be used as indices of a range, that is, `i8`, `i16`, `i32`, `i64`, and `isize`.
+pub trait ClipIndex: Copy + sealed::Sealed {
+    /// Converts the index to `isize`. A value that does not fit in `isize` saturates to `isize::MIN` or `isize::MAX`.
+    fn to_isize(self) -> isize;
+}
+
+macro_rules! impl_clip_index {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl ClipIndex for $t {
+                fn to_isize(self) -> isize {
+                    isize::try_from(self).unwrap_or(if self < 0 { isize::MIN } else { isize::MAX })
+                }
+            }
+        )*
+    };
+}
+
+impl_clip_index!(i8, i16, i32, i64, isize);
+
+/// A trait for the ranges that can be used to clip a slice.
+///
+/// It is implemented for all the standard ranges of `ClipIndex`, such as `1..-1`, `-2..`, `..=-1`, and `..`,
+/// and for pairs of `Bound`s, such as `(Bound::Excluded(-3), Bound::Included(-1))`.
+/// Implement it to use a user-defined range-like type with `Clip::by` and the other functions.
+pub trait ClipBounds {
+    /// The start bound of the range, which may be a negative index.
+    fn clip_start_bound(&self) -> Bound<isize>;
+
+    /// The end bound of the range, which may be a negative index.
+    fn clip_end_bound(&self) -> Bound<isize>;
+}
+
+macro_rules! impl_clip_bounds {
+    ( $( $t:ty ),* ) => {
+        $(
+            impl<I: ClipIndex> ClipBounds for $t {
+                fn clip_start_bound(&self) -> Bound<isize> {
+                    self.start_bound().cloned().map(ClipIndex::to_isize)
+                }
+                fn clip_end_bound(&self) -> Bound<isize> {
+                    self.end_bound().cloned().map(ClipIndex::to_isize)
+                }
+            }
+        )*
+    };
+}
+
+impl_clip_bounds!(
+    Range<I>,
+    RangeFrom<I>,
+    RangeTo<I>,
+    RangeInclusive<I>,
+    RangeToInclusive<I>,
+    (Bound<I>, Bound<I>)
+);
+
+impl ClipBounds for RangeFull {
+    fn clip_start_bound(&self) -> Bound<isize> {
+        Bound::Unbounded
+    }
+    fn clip_end_bound(&self) -> Bound<isize> {
+        Bound::Unbounded
+    }
+}
+
+/// A struct that serves as a prefix for the functions such as `by`, `mut_by`, `by_as_slice`, and `at`.
 pub struct Clip;
 
-/// A trait that defines `by` and `mut_by`.
+impl Clip {
+    /// The arguments are a slice and an index possibly negative. The return value is a reference to the item at the index.
+    /// If the index is a negative value, the position is interpreted as going backwards from the back end of the slice.
+    /// Returns `None` when the index is out of range, because a position of a single item cannot be clamped meaningfully.
+    pub fn at<T, S>(sl: &S, index: isize) -> Option<&T>
+    where
+        S: AsRef<[T]> + ?Sized,
+    {
+        let slice = sl.as_ref();
+        slice.get(item_index(index, slice.len())?)
+    }
+
+    /// Almost the same as function `at`, but takes a mutable slice as argument or returns a mutable reference.
+    pub fn at_mut<T, S>(sl: &mut S, index: isize) -> Option<&mut T>
+    where
+        S: AsMut<[T]> + ?Sized,
+    {
+        let slice = sl.as_mut();
+        let len = slice.len();
+        slice.get_mut(item_index(index, len)?)
+    }
+
+    /// The arguments are a slice and a position possibly negative. The return value is a pair of the slices
+    /// before and after the position. The position is clipped in the same way as `by`, so a position clamped to `0`
+    /// gives an empty first slice, and one clamped to the length gives an empty second slice.
+    pub fn split_at<T, S>(sl: &S, at: isize) -> (&[T], &[T])
+    where
+        S: AsRef<[T]> + ?Sized,
+    {
+        let slice = sl.as_ref();
+        slice.split_at(clip_traced(at, slice.len()))
+    }
+
+    /// Almost the same as function `split_at`, but takes a mutable slice as argument or return value.
+    pub fn split_at_mut<T, S>(sl: &mut S, at: isize) -> (&mut [T], &mut [T])
+    where
+        S: AsMut<[T]> + ?Sized,
+    {
+        let slice = sl.as_mut();
+        let len = slice.len();
+        slice.split_at_mut(clip_traced(at, len))
+    }
+
+    /// The arguments are a slice and a number of items. The return value is a slice of the first `n` items,
+    /// or the whole slice when `n` exceeds its length. The same as `by(sl, ..n)` but without casting `n` to `isize`.
+    pub fn first_n<T, S>(sl: &S, n: usize) -> &[T]
+    where
+        S: AsRef<[T]> + ?Sized,
+    {
+        let slice = sl.as_ref();
+        &slice[..clip_count(n, slice.len())]
+    }
+
+    /// Almost the same as function `first_n`, but takes a mutable slice as argument or return value.
+    pub fn first_n_mut<T, S>(sl: &mut S, n: usize) -> &mut [T]
+    where
+        S: AsMut<[T]> + ?Sized,
+    {
+        let slice = sl.as_mut();
+        let len = slice.len();
+        &mut slice[..clip_count(n, len)]
+    }
+
+    /// The arguments are a slice and a number of items. The return value is a slice of the last `n` items,
+    /// or the whole slice when `n` exceeds its length. The same as `by(sl, -(n as isize)..)` but without the cast.
+    pub fn last_n<T, S>(sl: &S, n: usize) -> &[T]
+    where
+        S: AsRef<[T]> + ?Sized,
+    {
+        let slice = sl.as_ref();
+        &slice[slice.len() - clip_count(n, slice.len())..]
+    }
+
+    /// Almost the same as function `last_n`, but takes a mutable slice as argument or return value.
+    pub fn last_n_mut<T, S>(sl: &mut S, n: usize) -> &mut [T]
+    where
+        S: AsMut<[T]> + ?Sized,
+    {
+        let slice = sl.as_mut();
+        let len = slice.len();
+        &mut slice[len - clip_count(n, len)..]
+    }
+
+    /// Wraps a slice to index it with negative indices, e.g. `Clip::wrap(&a[..])[-1]` or `Clip::wrap(&a[..])[1..-1]`.
+    pub fn wrap<'a, T>(sl: &'a [T]) -> Clipped<'a, T> {
+        Clipped(sl)
+    }
+
+    /// Almost the same as function `wrap`, but wraps a mutable slice to also assign items or slices through it.
+    pub fn wrap_mut<'a, T>(sl: &'a mut [T]) -> ClippedMut<'a, T> {
+        ClippedMut(sl)
+    }
+}
+
+/// A slice wrapper, created by `Clip::wrap`, that can be indexed with negative indices.
+///
+/// Indexing with an index gives the item as `Clip::at` does, but panics when the index is out of range,
+/// as normal indexing does. Indexing with a range gives a slice as `Clip::by` does.
+#[derive(Debug)]
+pub struct Clipped<'a, T>(&'a [T]);
+
+/// A mutable slice wrapper, created by `Clip::wrap_mut`, that can be indexed with negative indices.
+/// See `Clipped` for the details.
+#[derive(Debug)]
+pub struct ClippedMut<'a, T>(&'a mut [T]);
+
+// Panics in the same manner as normal indexing, for an item index out of range.
+fn index_out_of_range(index: isize, len: usize) -> ! {
+    panic!(
+        "clip_slice: index {} out of range for length {}",
+        index, len
+    )
+}
+
+impl<'a, T> Index<isize> for Clipped<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: isize) -> &T {
+        Clip::at(self.0, index).unwrap_or_else(|| index_out_of_range(index, self.0.len()))
+    }
+}
+
+impl<'a, T, R: ClipBounds> Index<R> for Clipped<'a, T> {
+    type Output = [T];
+
+    fn index(&self, range: R) -> &[T] {
+        Clip::by(self.0, range)
+    }
+}
+
+impl<'a, T> Index<isize> for ClippedMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: isize) -> &T {
+        Clip::at(&*self.0, index).unwrap_or_else(|| index_out_of_range(index, self.0.len()))
+    }
+}
+
+impl<'a, T> IndexMut<isize> for ClippedMut<'a, T> {
+    fn index_mut(&mut self, index: isize) -> &mut T {
+        let len = self.0.len();
+        Clip::at_mut(self.0, index).unwrap_or_else(|| index_out_of_range(index, len))
+    }
+}
+
+impl<'a, T, R: ClipBounds> Index<R> for ClippedMut<'a, T> {
+    type Output = [T];
+
+    fn index(&self, range: R) -> &[T] {
+        Clip::by(&*self.0, range)
+    }
+}
+
+impl<'a, T, R: ClipBounds> IndexMut<R> for ClippedMut<'a, T> {
+    fn index_mut(&mut self, range: R) -> &mut [T] {
+        Clip::mut_by(self.0, range)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Clip {
+    /// The arguments are a slice and a range possibly negative indices. The return value is a vector of cloned items.
+    /// When the start position comes after the end position after clipping, the items from the end position
+    /// up to the start position (exclusive) are returned in reverse order, instead of an empty vector.
+    /// So `3..0` on `[0, 1, 2, 3]` gives `[2, 1, 0]`.
+    pub fn by_rev<T, I, S>(sl: &S, range: Range<I>) -> Vec<T>
+    where
+        S: AsRef<[T]> + ?Sized,
+        T: Clone,
+        I: ClipIndex,
+    {
+        let slice = sl.as_ref();
+        let len = slice.len();
+        let start = clip_traced(range.start.to_isize(), len);
+        let end = clip_traced(range.end.to_isize(), len);
+        if start > end {
+            slice[end..start].iter().rev().cloned().collect()
+        } else {
+            slice[start..end].to_vec()
+        }
+    }
+
+    /// The arguments are a slice of a row-major matrix, its row width, and ranges of rows and columns possibly negative indices,
+    /// which may be any ranges accepted by `by`.
+    /// The return value is a vector of the clipped rows, each of which is clipped to the columns.
+    /// Negative row indices count from the bottom and negative column indices count from the right.
+    ///
+    /// When the length of the slice is not a multiple of `width`, the last row is a partial row.
+    /// The columns are resolved against `width`, and then truncated to the items the partial row actually has.
+    /// A zero `width` or a reversed range of rows gives an empty vector, and a reversed range of columns gives empty rows.
+    pub fn by_2d<T, S, R, C>(sl: &S, width: usize, rows: R, cols: C) -> Vec<&[T]>
+    where
+        S: AsRef<[T]> + ?Sized,
+        R: ClipBounds,
+        C: ClipBounds,
+    {
+        let slice = sl.as_ref();
+        if width == 0 {
+            return Vec::new();
+        }
+        let (row_start, row_end) = clip_range(&rows, slice.len().div_ceil(width));
+        let (col_start, col_end) = clip_range(&cols, width);
+        let col_end = col_end.max(col_start);
+        (row_start..row_end)
+            .map(|r| {
+                let row = &slice[r * width..((r + 1) * width).min(slice.len())];
+                &row[col_start.min(row.len())..col_end.min(row.len())]
+            })
+            .collect()
+    }
+
+    /// The arguments are a vector, a range possibly negative indices, and items to replace the range with.
+    /// Like Python's slice assignment, the clipped region is replaced by the items with `Vec::splice`,
+    /// so the vector may grow or shrink, and empty items just delete the region.
+    /// When the start position comes after the end position after clipping, the items are inserted at the start position.
+    pub fn splice_by<T, R, I>(vec: &mut Vec<T>, range: R, replacement: I)
+    where
+        R: ClipBounds,
+        I: IntoIterator<Item = T>,
+    {
+        let (start, end) = clip_range(&range, vec.len());
+        vec.splice(start..end.max(start), replacement);
+    }
+}
+
+/// A trait that defines `by` and `mut_by`, and the helpers `chunks`, `windows`, `fill_by`, and `copy_from_by` built on them.
 pub trait ClipSlice<T, R> {
-    /// The arguments are a slice and a range possibly negative indices. The return value is a slice. 
+    /// The arguments are a slice and a range possibly negative indices. The return value is a slice.
+    /// Anything that can be viewed as a slice, such as an array or a vector, can be passed by reference in place of the slice.
     /// If an index is a negative value, the position is interpreted as going backwards from the back end of the slice.
-    fn by<'t, S>(sl: S, range: R) -> &'t [T]
+    fn by<S>(sl: &S, range: R) -> &[T]
     where
-        S: Into<&'t [T]>;
+        S: AsRef<[T]> + ?Sized;
 
     /// Almost the same as function `by`, but takes an immutable slice as argument or return value.
-    fn mut_by<'t, S>(sl: S, range: R) -> &'t mut [T]
+    fn mut_by<S>(sl: &mut S, range: R) -> &mut [T]
+    where
+        S: AsMut<[T]> + ?Sized;
+
+    /// A helper function. Apply Clip::by to a slice and iterate over chunks of `size` items of the result.
+    /// Panics if `size` is zero, as `slice::chunks` does.
+    fn chunks<'t, S>(sl: &'t S, range: R, size: usize) -> Chunks<'t, T>
     where
-        S: Into<&'t mut [T]>;
+        S: AsRef<[T]> + ?Sized,
+    {
+        Self::by(sl, range).chunks(size)
+    }
+
+    /// A helper function. Apply Clip::by to a slice and iterate over windows of `size` items of the result.
+    /// Panics if `size` is zero, as `slice::windows` does.
+    fn windows<'t, S>(sl: &'t S, range: R, size: usize) -> Windows<'t, T>
+    where
+        S: AsRef<[T]> + ?Sized,
+    {
+        Self::by(sl, range).windows(size)
+    }
+
+    /// A helper function. Apply Clip::mut_by to a slice and fill the result with clones of `value`.
+    fn fill_by<S>(sl: &mut S, range: R, value: T)
+    where
+        S: AsMut<[T]> + ?Sized,
+        T: Clone,
+    {
+        Self::mut_by(sl, range).fill(value)
+    }
+
+    /// A helper function. Apply Clip::mut_by to a slice and copy the items of `src` into the result.
+    /// Panics if the length of `src` differs from that of the result, as `slice::copy_from_slice` does.
+    fn copy_from_by<S>(sl: &mut S, range: R, src: &[T])
+    where
+        S: AsMut<[T]> + ?Sized,
+        T: Copy,
+    {
+        Self::mut_by(sl, range).copy_from_slice(src)
+    }
 }
 
-impl<T> ClipSlice<T, Range<isize>> for Clip {
-    fn by<'t, S>(sl: S, range: Range<isize>) -> &'t [T]
+impl<T, R: ClipBounds> ClipSlice<T, R> for Clip {
+    fn by<S>(sl: &S, range: R) -> &[T]
     where
-        S: Into<&'t [T]>,
+        S: AsRef<[T]> + ?Sized,
     {
-        let slice = sl.into();
-        let len = slice.len();
-        let start = clip(range.start, len);
-        let end = clip(range.end, len);
+        let slice = sl.as_ref();
+        let (start, end) = clip_range(&range, slice.len());
         &slice[start..end]
     }
-    fn mut_by<'t, S>(sl: S, range: Range<isize>) -> &'t mut [T]
+    fn mut_by<S>(sl: &mut S, range: R) -> &mut [T]
     where
-        S: Into<&'t mut [T]>,
+        S: AsMut<[T]> + ?Sized,
     {
-        let slice = sl.into();
-        let len = slice.len();
-        let start = clip(range.start, len);
-        let end = clip(range.end, len);
+        let slice = sl.as_mut();
+        let (start, end) = clip_range(&range, slice.len());
         &mut slice[start..end]
     }
 }
 
-impl<T> ClipSlice<T, RangeFrom<isize>> for Clip {
-    fn by<'t, S>(sl: S, range: RangeFrom<isize>) -> &'t [T]
+/// A trait that defines `clip_by` and `clip_mut_by`, the method forms of `Clip::by` and `Clip::mut_by`.
+pub trait ClipSliceExt<T> {
+    /// Same as `Clip::by(self, range)`.
+    fn clip_by<R>(&self, range: R) -> &[T]
     where
-        S: Into<&'t [T]>,
+        Clip: ClipSlice<T, R>;
+
+    /// Same as `Clip::mut_by(self, range)`.
+    fn clip_mut_by<R>(&mut self, range: R) -> &mut [T]
+    where
+        Clip: ClipSlice<T, R>;
+}
+
+impl<T> ClipSliceExt<T> for [T] {
+    fn clip_by<R>(&self, range: R) -> &[T]
+    where
+        Clip: ClipSlice<T, R>,
     {
-        let slice = sl.into();
-        let len = slice.len();
-        let start = clip(range.start, len);
-        &slice[start..]
+        Clip::by(self, range)
     }
-    fn mut_by<'t, S>(sl: S, range: RangeFrom<isize>) -> &'t mut [T]
+    fn clip_mut_by<R>(&mut self, range: R) -> &mut [T]
     where
-        S: Into<&'t mut [T]>,
+        Clip: ClipSlice<T, R>,
     {
-        let slice = sl.into();
-        let len = slice.len();
-        let start = clip(range.start, len);
-        &mut slice[start..]
+        Clip::mut_by(self, range)
     }
 }
 
-impl<T> ClipSlice<T, RangeTo<isize>> for Clip {
-    fn by<'t, S>(sl: S, range: RangeTo<isize>) -> &'t [T]
+/// A trait that defines `try_by` and `try_mut_by`.
+pub trait TryClipSlice<T, R> {
+    /// Almost the same as function `by`, but returns `None` instead of clamping, when an index is out of `-len..=len`
+    /// or when the start position comes after the end position.
+    /// An inclusive end is checked as its exclusive equivalent, so it may be in `-(len + 1)..=len - 1`, e.g. `..=-5` for a length of 4.
+    fn try_by<S>(sl: &S, range: R) -> Option<&[T]>
+    where
+        S: AsRef<[T]> + ?Sized;
+
+    /// Almost the same as function `try_by`, but takes a mutable slice as argument or return value.
+    fn try_mut_by<S>(sl: &mut S, range: R) -> Option<&mut [T]>
+    where
+        S: AsMut<[T]> + ?Sized;
+}
+
+impl<T, R: ClipBounds> TryClipSlice<T, R> for Clip {
+    fn try_by<S>(sl: &S, range: R) -> Option<&[T]>
     where
-        S: Into<&'t [T]>,
+        S: AsRef<[T]> + ?Sized,
     {
-        let slice = sl.into();
-        let len = slice.len();
-        let end = clip(range.end, len);
-        &slice[..end]
+        let slice = sl.as_ref();
+        let (start, end) = try_clip_range(&range, slice.len())?;
+        Some(&slice[start..end])
     }
-    fn mut_by<'t, S>(sl: S, range: RangeTo<isize>) -> &'t mut [T]
+    fn try_mut_by<S>(sl: &mut S, range: R) -> Option<&mut [T]>
     where
-        S: Into<&'t mut [T]>,
+        S: AsMut<[T]> + ?Sized,
     {
-        let slice = sl.into();
-        let len = slice.len();
-        let end = clip(range.end, len);
-        &mut slice[..end]
+        let slice = sl.as_mut();
+        let (start, end) = try_clip_range(&range, slice.len())?;
+        Some(&mut slice[start..end])
     }
 }
 
-impl<T> ClipSlice<T, RangeFull> for Clip {
-    fn by<'t, S>(sl: S, _range: RangeFull) -> &'t [T]
+/// A trait that defines `strict_by` and `strict_mut_by`.
+pub trait StrictClipSlice<T, R> {
+    /// Almost the same as function `by`, but panics instead of clamping, when an index is out of `-len..=len`
+    /// or when the start position comes after the end position.
+    /// An inclusive end is checked in the same way as `try_by`, so `0..=4` panics for a length of 4, like `&a[0..=4]`.
+    fn strict_by<S>(sl: &S, range: R) -> &[T]
+    where
+        S: AsRef<[T]> + ?Sized;
+
+    /// Almost the same as function `strict_by`, but takes a mutable slice as argument or return value.
+    fn strict_mut_by<S>(sl: &mut S, range: R) -> &mut [T]
+    where
+        S: AsMut<[T]> + ?Sized;
+}
+
+impl<T, R: ClipBounds> StrictClipSlice<T, R> for Clip {
+    fn strict_by<S>(sl: &S, range: R) -> &[T]
     where
-        S: Into<&'t [T]>,
+        S: AsRef<[T]> + ?Sized,
     {
-        let slice = sl.into();
-        &slice[..]
+        let slice = sl.as_ref();
+        let (start, end) = strict_clip_range(&range, slice.len());
+        &slice[start..end]
     }
-    fn mut_by<'t, S>(sl: S, _range: RangeFull) -> &'t mut [T]
+    fn strict_mut_by<S>(sl: &mut S, range: R) -> &mut [T]
     where
-        S: Into<&'t mut [T]>,
+        S: AsMut<[T]> + ?Sized,
     {
-        let slice = sl.into();
-        &mut slice[..]
+        let slice = sl.as_mut();
+        let (start, end) = strict_clip_range(&range, slice.len());
+        &mut slice[start..end]
     }
 }
 
-/// A trait that defines `by_as_slice` and `by_as_mut_slice`.
+/// A trait that defines `clip_get` and `clip_get_mut`.
+///
+/// They are in between `by` and `try_by`. Like `slice::get`, they decline to produce a truncated slice,
+/// but a positive index beyond the back end is still clamped to the length. The return value is `None` only when
+/// * a negative index goes beyond the front end, i.e., it is less than `-len`, or
+/// * the start position comes after the end position, after clipping.
+pub trait ClipGet<T, R> {
+    /// Almost the same as function `by`, but returns `None` for the ranges described above.
+    fn clip_get<S>(sl: &S, range: R) -> Option<&[T]>
+    where
+        S: AsRef<[T]> + ?Sized;
+
+    /// Almost the same as function `clip_get`, but takes a mutable slice as argument or return value.
+    fn clip_get_mut<S>(sl: &mut S, range: R) -> Option<&mut [T]>
+    where
+        S: AsMut<[T]> + ?Sized;
+}
+
+impl<T, R: ClipBounds> ClipGet<T, R> for Clip {
+    fn clip_get<S>(sl: &S, range: R) -> Option<&[T]>
+    where
+        S: AsRef<[T]> + ?Sized,
+    {
+        let slice = sl.as_ref();
+        let (start, end) = get_clip_range(&range, slice.len())?;
+        Some(&slice[start..end])
+    }
+    fn clip_get_mut<S>(sl: &mut S, range: R) -> Option<&mut [T]>
+    where
+        S: AsMut<[T]> + ?Sized,
+    {
+        let slice = sl.as_mut();
+        let (start, end) = get_clip_range(&range, slice.len())?;
+        Some(&mut slice[start..end])
+    }
+}
+
+/// A trait that defines `len_of` and `resolve`.
+pub trait ClipLen<R> {
+    /// The arguments are a length of a slice and a range possibly negative indices.
+    /// The return value is the length of the slice that Clip::by would return for them.
+    fn len_of(len: usize, range: R) -> usize;
+
+    /// The arguments are a length of a slice and a range possibly negative indices.
+    /// The return value is the pair of the start and end positions that Clip::by would slice with,
+    /// e.g., `(start, len)` for `start..`, `(0, end)` for `..end`, and `(0, len)` for `..`.
+    /// The start may be greater than the end for a reversed range such as `3..1`.
+    fn resolve(len: usize, range: R) -> (usize, usize);
+}
+
+impl<R: ClipBounds> ClipLen<R> for Clip {
+    fn len_of(len: usize, range: R) -> usize {
+        let (start, end) = clip_range(&range, len);
+        end.saturating_sub(start)
+    }
+    fn resolve(len: usize, range: R) -> (usize, usize) {
+        clip_range(&range, len)
+    }
+}
+
+/// A trait that defines `by_str`.
+pub trait ClipStr<R> {
+    /// The arguments are a string and a range possibly negative indices. The return value is a string.
+    /// Unlike `by`, the indices are counted in characters rather than bytes, so the result is always a valid `&str`.
+    fn by_str(s: &str, range: R) -> &str;
+}
+
+impl<R: ClipBounds> ClipStr<R> for Clip {
+    fn by_str(s: &str, range: R) -> &str {
+        let (start, end) = clip_range(&range, s.chars().count());
+        &s[char_to_byte(s, start)..char_to_byte(s, end)]
+    }
+}
+
+/// A trait that defines `by_as_slice`, `by_as_mut_slice`, `by_cloned`, `by_cow`, and `by_cow_mut`.
+#[cfg(feature = "alloc")]
+#[allow(clippy::ptr_arg)]
 pub trait ClipAsSlice<T, R> {
     /// A helper function. Generate a slice and apply Clip::by to it.
-    fn by_as_slice<'t>(vec: &'t Vec<T>, range: R) -> &'t [T];
-    
+    fn by_as_slice(vec: &Vec<T>, range: R) -> &[T];
+
     /// A helper function. Generate a mutable slice and apply Clip::mut_by to it.
-    fn by_as_mut_slice<'t>(vec: &'t mut Vec<T>, range: R) -> &'t mut [T];
-}
+    fn by_as_mut_slice(vec: &mut Vec<T>, range: R) -> &mut [T];
 
-impl<T> ClipAsSlice<T, Range<isize>> for Clip {
-    fn by_as_slice<'t>(vec: &'t Vec<T>, range: Range<isize>) -> &'t [T] {
-        let slice = vec.as_slice();
-        Clip::by(slice, range)
+    /// A helper function. Apply Clip::by to a slice and clone the result into a new vector.
+    fn by_cloned<S>(sl: &S, range: R) -> Vec<T>
+    where
+        S: AsRef<[T]> + ?Sized,
+        T: Clone,
+        Clip: ClipSlice<T, R>,
+    {
+        Clip::by(sl, range).to_vec()
     }
-    fn by_as_mut_slice<'t>(vec: &'t mut Vec<T>, range: Range<isize>) -> &'t mut [T] {
-        let slice = vec.as_mut_slice();
-        Clip::mut_by(slice, range)
+
+    /// A helper function. Borrow a slice from a `Cow`, either borrowed or owned, and apply Clip::by to it.
+    fn by_cow<'t, 'c>(cow: &'t Cow<'c, [T]>, range: R) -> &'t [T]
+    where
+        T: Clone,
+        Clip: ClipSlice<T, R>,
+    {
+        Clip::by(cow.as_ref(), range)
+    }
+
+    /// A helper function. Get a mutable slice from a `Cow` with `Cow::to_mut` and apply Clip::mut_by to it.
+    /// A borrowed `Cow` is cloned into an owned one at this point.
+    fn by_cow_mut<'t, 'c>(cow: &'t mut Cow<'c, [T]>, range: R) -> &'t mut [T]
+    where
+        T: Clone,
+        Clip: ClipSlice<T, R>,
+    {
+        Clip::mut_by(cow.to_mut(), range)
     }
 }
 
-impl<T> ClipAsSlice<T, RangeFrom<isize>> for Clip {
-    fn by_as_slice<'t>(vec: &'t Vec<T>, range: RangeFrom<isize>) -> &'t [T] {
+#[cfg(feature = "alloc")]
+impl<T, R: ClipBounds> ClipAsSlice<T, R> for Clip {
+    fn by_as_slice(vec: &Vec<T>, range: R) -> &[T] {
         let slice = vec.as_slice();
         Clip::by(slice, range)
     }
-    fn by_as_mut_slice<'t>(vec: &'t mut Vec<T>, range: RangeFrom<isize>) -> &'t mut [T] {
+    fn by_as_mut_slice(vec: &mut Vec<T>, range: R) -> &mut [T] {
         let slice = vec.as_mut_slice();
         Clip::mut_by(slice, range)
     }
 }
 
-impl<T> ClipAsSlice<T, RangeTo<isize>> for Clip {
-    fn by_as_slice<'t>(vec: &'t Vec<T>, range: RangeTo<isize>) -> &'t [T] {
-        let slice = vec.as_slice();
+/// A trait that defines `by_deque` and `mut_by_deque`.
+///
+/// Both functions take a mutable reference to the deque, even the one returning an immutable slice,
+/// because the items of a `VecDeque` are not always contiguous in memory and have to be rearranged
+/// with `VecDeque::make_contiguous` before a single slice can refer to them.
+#[cfg(feature = "alloc")]
+pub trait ClipDeque<T, R> {
+    /// A helper function. Make the deque contiguous and apply Clip::by to it.
+    fn by_deque(deque: &mut VecDeque<T>, range: R) -> &[T];
+
+    /// A helper function. Make the deque contiguous and apply Clip::mut_by to it.
+    fn mut_by_deque(deque: &mut VecDeque<T>, range: R) -> &mut [T];
+}
+
+#[cfg(feature = "alloc")]
+impl<T, R> ClipDeque<T, R> for Clip
+where
+    Clip: ClipSlice<T, R>,
+{
+    fn by_deque(deque: &mut VecDeque<T>, range: R) -> &[T] {
+        let slice = deque.make_contiguous();
         Clip::by(slice, range)
     }
-    fn by_as_mut_slice<'t>(vec: &'t mut Vec<T>, range: RangeTo<isize>) -> &'t mut [T] {
-        let slice = vec.as_mut_slice();
+    fn mut_by_deque(deque: &mut VecDeque<T>, range: R) -> &mut [T] {
+        let slice = deque.make_contiguous();
         Clip::mut_by(slice, range)
     }
 }
 
-impl<T> ClipAsSlice<T, RangeFull> for Clip {
-    fn by_as_slice<'t>(vec: &'t Vec<T>, _range: RangeFull) -> &'t [T] {
-        let slice = vec.as_slice();
-        &slice[..]
-    }
-    fn by_as_mut_slice<'t>(vec: &'t mut Vec<T>, _range: RangeFull) -> &'t mut [T] {
-        let slice = vec.as_mut_slice();
-        &mut slice[..]
-    }
+/// A macro to write clipping tersely. It works with arrays, vectors, and slices.
+///
+/// * `clip!(a, <range>)` is the same as `Clip::by(&a[..], <range>)`.
+/// * `clip!(mut a, <range>)` is the same as `Clip::mut_by(&mut a[..], <range>)`.
+/// * `clip!(a[<index>])` is a reference to the item at the index, which may be negative.
+/// * `clip!(mut a[<index>])` is a mutable reference to the item at the index.
+///
+/// Accessing an item out of range panics.
+#[macro_export]
+macro_rules! clip {
+    ( mut $a:ident [ $index:expr ] ) => {
+        $crate::Clip::at_mut(&mut $a[..], $index).expect("clip_slice: index out of range")
+    };
+    ( $a:ident [ $index:expr ] ) => {
+        $crate::Clip::at(&$a[..], $index).expect("clip_slice: index out of range")
+    };
+    ( mut $a:expr , $range:expr ) => {
+        <$crate::Clip as $crate::ClipSlice<_, _>>::mut_by(&mut $a[..], $range)
+    };
+    ( $a:expr , $range:expr ) => {
+        <$crate::Clip as $crate::ClipSlice<_, _>>::by(&$a[..], $range)
+    };
 }
 
 #[cfg(test)]
+mod inlined;
+
+#[cfg(test)]
+#[allow(clippy::reversed_empty_ranges, clippy::useless_conversion)]
 mod tests {
     use super::*;
+    use std::vec;
+    use std::vec::Vec;
+
+    const CLIPPED: usize = clip(-2, 4);
+    const TABLE: [usize; 3] = [clip(-10, 4), clip(1, 4), clip(10, 4)];
+
+    #[test]
+    fn clip_at() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::at(&a, 0), Some(&0));
+        assert_eq!(Clip::at(&a, -4), Some(&0));
+        assert_eq!(Clip::at(&a, 3), Some(&3));
+        assert_eq!(Clip::at(&a, -1), Some(&3));
+        assert_eq!(Clip::at(&a, 4), None);
+        assert_eq!(Clip::at(&a, -5), None);
+        assert_eq!(Clip::at(&a, -100), None);
+        assert_eq!(Clip::at(&a[..0], 0), None);
+
+        let mut a = [0, 1, 2, 3];
+
+        *Clip::at_mut(&mut a, 0).unwrap() = 10;
+        *Clip::at_mut(&mut a, -1).unwrap() = 30;
+        assert_eq!(a, [10, 1, 2, 30]);
+        assert_eq!(Clip::at_mut(&mut a, 4), None);
+        assert_eq!(Clip::at_mut(&mut a, -100), None);
+    }
+
+    #[test]
+    fn clip_split_at() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::split_at(&a, 1), (&[0][..], &[1, 2, 3][..]));
+        assert_eq!(Clip::split_at(&a, -2), (&[0, 1][..], &[2, 3][..]));
+        assert_eq!(Clip::split_at(&a, -10), (&[][..], &[0, 1, 2, 3][..]));
+        assert_eq!(Clip::split_at(&a, 10), (&[0, 1, 2, 3][..], &[][..]));
+
+        let mut a = [0, 1, 2, 3];
+
+        let (left, right) = Clip::split_at_mut(&mut a, -1);
+        left[0] = 10;
+        right[0] = 30;
+        assert_eq!(a, [10, 1, 2, 30]);
+
+        let (left, right) = Clip::split_at_mut(&mut a, 10);
+        assert_eq!(left, &[10, 1, 2, 30]);
+        assert!(right.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_by_rev() {
+        let a = [0, 1, 2, 3, 4, 5];
+
+        assert_eq!(Clip::by_rev(&a, 1..4), vec![1, 2, 3]);
+        assert_eq!(Clip::by_rev(&a, 1..-1), vec![1, 2, 3, 4]);
+        assert_eq!(Clip::by_rev(&a, 3..0), vec![2, 1, 0]);
+        assert_eq!(Clip::by_rev(&a, -1..1), vec![4, 3, 2, 1]);
+        assert_eq!(Clip::by_rev(&a, 10..-10), vec![5, 4, 3, 2, 1, 0]);
+        assert_eq!(Clip::by_rev(&a, 2..2), vec![]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_by_2d() {
+        #[rustfmt::skip]
+        let m = [
+            0, 1, 2, 3,
+            4, 5, 6, 7,
+            8, 9, 10, 11,
+        ];
+
+        assert_eq!(Clip::by_2d(&m, 4, 1..-1, 1..-1), vec![&[5, 6][..]]);
+        assert_eq!(
+            Clip::by_2d(&m, 4, -2..3, -2..4),
+            vec![&[6, 7][..], &[10, 11][..]]
+        );
+        assert_eq!(Clip::by_2d(&m, 4, 0..1, 0..10), vec![&[0, 1, 2, 3][..]]);
+        assert_eq!(Clip::by_2d(&m, 4, 2..1, 0..4), Vec::<&[i32]>::new());
+        assert_eq!(Clip::by_2d(&m, 0, 0..1, 0..1), Vec::<&[i32]>::new());
+
+        // the last row is a partial row
+        let m = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        assert_eq!(Clip::by_2d(&m, 4, -2.., 1..-1), vec![&[5, 6][..], &[9][..]]);
+        assert_eq!(Clip::by_2d(&m, 4, -1.., -2..4), vec![&[][..]]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_splice_by() {
+        let mut v = vec![0, 1, 2, 3];
+        Clip::splice_by(&mut v, 1..-1, [9, 9, 9]);
+        assert_eq!(v, vec![0, 9, 9, 9, 3]);
+
+        let mut v = vec![0, 1, 2, 3];
+        Clip::splice_by(&mut v, -3.., [9]);
+        assert_eq!(v, vec![0, 9]);
+
+        let mut v = vec![0, 1, 2, 3];
+        Clip::splice_by(&mut v, 1..-1, []);
+        assert_eq!(v, vec![0, 3]);
+
+        let mut v = vec![0, 1, 2, 3];
+        Clip::splice_by(&mut v, 3..1, [9, 9]);
+        assert_eq!(v, vec![0, 1, 2, 9, 9, 3]);
+
+        let mut v = vec![0, 1, 2, 3];
+        Clip::splice_by(&mut v, -10..10, vec![5]);
+        assert_eq!(v, vec![5]);
+    }
+
+    #[test]
+    fn clip_first_last_n() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::first_n(&a, 0), &[]);
+        assert_eq!(Clip::first_n(&a, 2), &[0, 1]);
+        assert_eq!(Clip::first_n(&a, 4), &[0, 1, 2, 3]);
+        assert_eq!(Clip::first_n(&a, 10), &[0, 1, 2, 3]);
+        assert_eq!(Clip::last_n(&a, 0), &[]);
+        assert_eq!(Clip::last_n(&a, 2), &[2, 3]);
+        assert_eq!(Clip::last_n(&a, 4), &[0, 1, 2, 3]);
+        assert_eq!(Clip::last_n(&a, 10), &[0, 1, 2, 3]);
+        assert_eq!(Clip::last_n(&a, usize::MAX), &[0, 1, 2, 3]);
+
+        let mut a = [0, 1, 2, 3];
+
+        Clip::first_n_mut(&mut a, 1)[0] = 10;
+        Clip::last_n_mut(&mut a, 1)[0] = 30;
+        assert_eq!(a, [10, 1, 2, 30]);
+        assert_eq!(Clip::first_n_mut(&mut a, 10), &[10, 1, 2, 30]);
+        assert!(Clip::last_n_mut(&mut a, 0).is_empty());
+    }
+
+    #[test]
+    fn clip_wrap() {
+        let a = [0, 1, 2, 3];
+        let c = Clip::wrap(&a[..]);
+
+        assert_eq!(c[0], 0);
+        assert_eq!(c[-1], 3);
+        assert_eq!(c[-4], 0);
+        assert_eq!(&c[1..-1], &[1, 2]);
+        assert_eq!(&c[-2..], &[2, 3]);
+        assert_eq!(&c[..=-2], &[0, 1, 2]);
+        assert_eq!(&c[..], &[0, 1, 2, 3]);
+
+        let mut a = [0, 1, 2, 3];
+        let mut c = Clip::wrap_mut(&mut a[..]);
+
+        c[-1] = 30;
+        c[1..-1][0] = 10;
+        assert_eq!(c[-1], 30);
+        assert_eq!(&c[..2], &[0, 10]);
+        c[-2..].copy_from_slice(&[20, 40]);
+        assert_eq!(a, [0, 10, 20, 40]);
+    }
+
+    #[test]
+    #[should_panic(expected = "clip_slice: index -5 out of range for length 4")]
+    fn clip_wrap_out_of_range() {
+        let a = [0, 1, 2, 3];
+
+        let _ = Clip::wrap(&a[..])[-5];
+    }
+
+    #[test]
+    #[should_panic(expected = "clip_slice: index 4 out of range for length 4")]
+    fn clip_wrap_mut_out_of_range() {
+        let mut a = [0, 1, 2, 3];
+
+        Clip::wrap_mut(&mut a[..])[4] = 40;
+    }
+
+    #[test]
+    fn clip_const() {
+        assert_eq!(CLIPPED, 2);
+        assert_eq!(TABLE, [0, 1, 4]);
+
+        assert_eq!(clip(0, 4), 0);
+        assert_eq!(clip(4, 4), 4);
+        assert_eq!(clip(-1, 4), 3);
+        assert_eq!(clip(-4, 4), 0);
+        assert_eq!(clip(-5, 4), 0);
+        assert_eq!(clip(5, 4), 4);
+        assert_eq!(clip(-1, 0), 0);
+        assert_eq!(clip(isize::MIN, 4), 0);
+        assert_eq!(clip(isize::MAX, 4), 4);
+    }
 
     #[test]
     fn clip_range_simple() {
@@ -226,6 +1152,51 @@ mod tests {
         assert_eq!(s, &[0, 1, 2]);
     }
 
+    #[test]
+    fn clip_range_inclusive() {
+        let a = [0, 1, 2, 3];
+
+        let s = Clip::by(&a[..], -3..=-1);
+        assert_eq!(s, &[1, 2, 3]);
+
+        let s = Clip::by(&a[..], 0..=0);
+        assert_eq!(s, &[0]);
+
+        let mut a = [0, 1, 2, 3];
+
+        let s = Clip::mut_by(&mut a[..], -3..=-1);
+        s[0] = 10;
+        assert_eq!(a, [0, 10, 2, 3]);
+    }
+
+    #[test]
+    fn clip_range_to_inclusive() {
+        let a = [0, 1, 2, 3];
+
+        let s = Clip::by(&a[..], ..=-1);
+        assert_eq!(s, &[0, 1, 2, 3]);
+
+        let a = [0, 1, 2, 3];
+
+        let s = Clip::by(&a[..], ..=1);
+        assert_eq!(s, &[0, 1]);
+
+        let s = Clip::by(&a[..], ..=-4);
+        assert_eq!(s, &[0]);
+
+        let s = Clip::by(&a[..], ..=-5);
+        assert_eq!(s, &[]);
+
+        let s = Clip::by(&a[..], ..=-100);
+        assert_eq!(s, &[]);
+
+        let s = Clip::by(&a[..], -100..=-5);
+        assert_eq!(s, &[]);
+
+        assert_eq!(Clip::len_of(4, ..=-100), 0);
+        assert_eq!(Clip::by_str("abcd", ..=-10), "");
+    }
+
     #[test]
     fn clip_range_full() {
         let a = [0, 1, 2, 3];
@@ -234,7 +1205,475 @@ mod tests {
         assert_eq!(s, &[0, 1, 2, 3]);
     }
 
+    // An oracle for the positions `Clip::by` slices with, independent of `clip`. Each bound is resolved in `i128`,
+    // a negative one from the back end and an inclusive end or an excluded start moved by one, and only then clamped to `0..=len`.
+    fn reference_range<R: ClipBounds>(len: usize, range: &R) -> (usize, usize) {
+        let n = len as i128;
+        let resolve = |pos: isize| {
+            if pos < 0 {
+                n + pos as i128
+            } else {
+                pos as i128
+            }
+        };
+        let clamp = |p: i128| p.clamp(0, n) as usize;
+        let start = match range.clip_start_bound() {
+            Bound::Included(pos) => clamp(resolve(pos)),
+            Bound::Excluded(pos) => clamp(resolve(pos) + 1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.clip_end_bound() {
+            Bound::Included(pos) => clamp(resolve(pos) + 1),
+            Bound::Excluded(pos) => clamp(resolve(pos)),
+            Bound::Unbounded => len,
+        };
+        (start, end)
+    }
+
+    #[test]
+    fn clip_reference_range() {
+        assert_eq!(reference_range(4, &(1..-1)), (1, 3));
+        assert_eq!(reference_range(4, &(-3..=-1)), (1, 4));
+        assert_eq!(reference_range(4, &(..=-4)), (0, 1));
+        assert_eq!(reference_range(4, &(..=-5)), (0, 0));
+        assert_eq!(reference_range(4, &(..=-100)), (0, 0));
+        assert_eq!(reference_range(4, &(..=9)), (0, 4));
+        assert_eq!(reference_range(4, &(-10..)), (0, 4));
+        assert_eq!(
+            reference_range(4, &(Bound::Excluded(-100), Bound::Unbounded)),
+            (0, 4)
+        );
+        assert_eq!(
+            reference_range(4, &(Bound::Excluded(-1), Bound::Unbounded)),
+            (4, 4)
+        );
+        assert_eq!(reference_range(4, &(3..1)), (3, 1));
+        assert_eq!(reference_range(0, &(..=-1)), (0, 0));
+    }
+
+    #[test]
+    fn clip_identical_results_across_ranges() {
+        let mut positions = vec![isize::MIN, -100, 100, isize::MAX];
+        positions.extend(-8..=8isize);
+        for len in 0..=6usize {
+            let v: Vec<u8> = (0..len as u8).collect();
+            macro_rules! check {
+                ( $range:expr ) => {
+                    let (start, end) = reference_range(len, &$range);
+                    assert_eq!(Clip::resolve(len, $range), (start, end));
+                    assert_eq!(inlined::inlined_range(len, &$range), (start, end));
+                    if start <= end {
+                        assert_eq!(Clip::by(&v, $range), &v[start..end]);
+                        assert_eq!(inlined::inlined_by(&v, $range), &v[start..end]);
+                    }
+                };
+            }
+            for &s in &positions {
+                for &e in &positions {
+                    check!(s..e);
+                    check!(s..=e);
+                    check!((Bound::Excluded(s), Bound::Included(e)));
+                    check!((Bound::Excluded(s), Bound::Excluded(e)));
+                }
+                check!(s..);
+                check!(..s);
+                check!(..=s);
+                check!((Bound::Excluded(s), Bound::Unbounded));
+            }
+            check!(..);
+        }
+    }
+
+    #[test]
+    fn clip_mixed_bounds() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(
+            Clip::by(&a, (Bound::Excluded(-4), Bound::Included(-2))),
+            &[1, 2]
+        );
+        assert_eq!(
+            Clip::by(&a, (Bound::Excluded(0), Bound::Unbounded)),
+            &[1, 2, 3]
+        );
+        assert_eq!(Clip::by(&a, (Bound::Excluded(-1), Bound::Unbounded)), &[]);
+        assert_eq!(
+            Clip::by(&a, (Bound::Excluded(-5), Bound::Unbounded)),
+            &[0, 1, 2, 3]
+        );
+        assert_eq!(
+            Clip::by(&a, (Bound::Excluded(-100), Bound::Unbounded)),
+            &[0, 1, 2, 3]
+        );
+        assert_eq!(
+            Clip::by(&a, (Bound::Excluded(-100), Bound::Included(-100))),
+            &[]
+        );
+        assert_eq!(
+            Clip::by(&a, (Bound::Unbounded, Bound::Excluded(-1))),
+            &[0, 1, 2]
+        );
+        assert_eq!(
+            Clip::by(&a, (Bound::<isize>::Unbounded, Bound::Unbounded)),
+            &[0, 1, 2, 3]
+        );
+        assert_eq!(
+            Clip::try_by(&a, (Bound::Excluded(-10), Bound::Unbounded)),
+            None
+        );
+        assert_eq!(Clip::len_of(4, (Bound::Excluded(0), Bound::Included(2))), 2);
+        assert_eq!(
+            Clip::by_str("abcd", (Bound::Excluded(0), Bound::Excluded(-1))),
+            "bc"
+        );
+
+        let mut a = [0, 1, 2, 3];
+
+        Clip::mut_by(&mut a, (Bound::Excluded(-2), Bound::Included(-1)))[0] = 30;
+        assert_eq!(a, [0, 1, 2, 30]);
+    }
+
+    #[test]
+    fn clip_user_defined_range() {
+        struct Middle;
+
+        impl ClipBounds for Middle {
+            fn clip_start_bound(&self) -> Bound<isize> {
+                Bound::Included(1)
+            }
+            fn clip_end_bound(&self) -> Bound<isize> {
+                Bound::Excluded(-1)
+            }
+        }
+
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::by(&a, Middle), &[1, 2]);
+        assert_eq!(a.clip_by(Middle), &[1, 2]);
+    }
+
+    #[test]
+    fn clip_index_types() {
+        let a = [0, 1, 2, 3];
+
+        let (start, end): (i32, i32) = (1, -1);
+        assert_eq!(Clip::by(&a[..], start..end), &[1, 2]);
+        assert_eq!(Clip::by(&a[..], -2i64..), &[2, 3]);
+        assert_eq!(Clip::by(&a[..], ..-1i8), &[0, 1, 2]);
+        assert_eq!(Clip::by(&a[..], -3i16..=-2), &[1, 2]);
+        assert_eq!(Clip::by(&a[..], i64::MIN..i64::MAX), &[0, 1, 2, 3]);
+        assert_eq!(Clip::try_by(&a[..], -2i64..), Some(&[2, 3][..]));
+        assert_eq!(Clip::by_str("abcd", 1i32..-1), "bc");
+
+        let mut a = [0, 1, 2, 3];
+
+        Clip::mut_by(&mut a[..], -1i64..)[0] = 30;
+        assert_eq!(a, [0, 1, 2, 30]);
+    }
+
+    #[test]
+    fn clip_index_saturation() {
+        assert_eq!(i64::MAX.to_isize(), isize::MAX);
+        assert_eq!(i64::MIN.to_isize(), isize::MIN);
+        #[cfg(target_pointer_width = "32")]
+        {
+            assert_eq!((1i64 << 40).to_isize(), isize::MAX);
+            assert_eq!((-1i64 << 40).to_isize(), isize::MIN);
+        }
+        assert_eq!((-5i8).to_isize(), -5);
+        assert_eq!(7i32.to_isize(), 7);
+
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::by(&a, i64::MIN..-1), &[0, 1, 2]);
+        assert_eq!(Clip::by(&a, 1..i64::MAX), &[1, 2, 3]);
+        assert_eq!(Clip::by(&a, i64::MAX..), &[]);
+        assert_eq!(Clip::by(&a, ..=i64::MIN), &[]);
+        assert_eq!(Clip::by(&a, -(1i64 << 40)..(1i64 << 40)), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn clip_chunks_windows() {
+        let a = [0, 1, 2, 3, 4, 5];
+
+        assert!(Clip::windows(&a, 1..-1, 2).eq(Clip::by(&a, 1..-1).windows(2)));
+        assert!(Clip::chunks(&a, 1..-1, 2).eq(Clip::by(&a, 1..-1).chunks(2)));
+        assert!(Clip::windows(&a, -3.., 2).eq([&[3, 4][..], &[4, 5][..]]));
+        assert!(Clip::chunks(&a, ..-1, 2).eq([&[0, 1][..], &[2, 3][..], &[4][..]]));
+        assert_eq!(Clip::windows(&a, -1.., 2).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clip_windows_zero_size() {
+        let a = [0, 1, 2, 3];
+
+        let _ = Clip::windows(&a, .., 0);
+    }
+
+    #[test]
+    fn clip_fill_copy_from() {
+        let mut a = [0, 1, 2, 3, 4, 5];
+
+        Clip::fill_by(&mut a, 1..-1, 9);
+        assert_eq!(a, [0, 9, 9, 9, 9, 5]);
+
+        Clip::copy_from_by(&mut a, -3.., &[30, 40, 50]);
+        assert_eq!(a, [0, 9, 9, 30, 40, 50]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clip_copy_from_length_mismatch() {
+        let mut a = [0, 1, 2, 3, 4, 5];
+
+        Clip::copy_from_by(&mut a, -3.., &[30, 40]);
+    }
+
+    #[test]
+    fn clip_slice_ext() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(a.clip_by(1..-1), Clip::by(&a[..], 1..-1));
+        assert_eq!(a.clip_by(-2..), Clip::by(&a[..], -2..));
+        assert_eq!(a.clip_by(..-1), Clip::by(&a[..], ..-1));
+        assert_eq!(a.clip_by(..), Clip::by(&a[..], ..));
+        assert_eq!(a.clip_by(-3..=-1), Clip::by(&a[..], -3..=-1));
+        assert_eq!(a.clip_by(..=-2), Clip::by(&a[..], ..=-2));
+
+        let v: Vec<i32> = (0..4).collect();
+        assert_eq!(v.clip_by(1..-1), &[1, 2]);
+
+        let mut a = [0, 1, 2, 3];
+        let mut b = [0, 1, 2, 3];
+
+        a.clip_mut_by(..-2)[1] = 10;
+        Clip::mut_by(&mut b[..], ..-2)[1] = 10;
+        assert_eq!(a, b);
+        a.clip_mut_by(-2..)[0] = 20;
+        Clip::mut_by(&mut b[..], -2..)[0] = 20;
+        assert_eq!(a, b);
+        a.clip_mut_by(1..-1)[1] = 30;
+        Clip::mut_by(&mut b[..], 1..-1)[1] = 30;
+        assert_eq!(a, b);
+        a.clip_mut_by(..)[3] = 40;
+        Clip::mut_by(&mut b[..], ..)[3] = 40;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn try_clip_range() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::try_by(&a[..], 1..-1), Some(&[1, 2][..]));
+        assert_eq!(Clip::try_by(&a[..], -4..4), Some(&[0, 1, 2, 3][..]));
+        assert_eq!(Clip::try_by(&a[..], -10..2), None);
+        assert_eq!(Clip::by(&a[..], -10..2), &[0, 1]);
+        assert_eq!(Clip::try_by(&a[..], 0..5), None);
+        assert_eq!(Clip::by(&a[..], 0..5), &[0, 1, 2, 3]);
+        assert_eq!(Clip::try_by(&a[..], 3..1), None);
+        assert_eq!(Clip::try_by(&a[..], 2..2), Some(&[][..]));
+
+        let mut a = [0, 1, 2, 3];
+
+        let s = Clip::try_mut_by(&mut a[..], -2..4).unwrap();
+        s[0] = 20;
+        assert_eq!(a, [0, 1, 20, 3]);
+        assert_eq!(Clip::try_mut_by(&mut a[..], -5..4), None);
+    }
+
+    #[test]
+    fn try_clip_range_from_to() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::try_by(&a[..], -2..), Some(&[2, 3][..]));
+        assert_eq!(Clip::try_by(&a[..], -10..), None);
+        assert_eq!(Clip::by(&a[..], -10..), &[0, 1, 2, 3]);
+        assert_eq!(Clip::try_by(&a[..], ..-1), Some(&[0, 1, 2][..]));
+        assert_eq!(Clip::try_by(&a[..], ..-10), None);
+        assert_eq!(Clip::by(&a[..], ..-10), &[]);
+        assert_eq!(Clip::try_by(&a[..], ..), Some(&[0, 1, 2, 3][..]));
+    }
+
+    #[test]
+    fn try_clip_range_inclusive() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::try_by(&a[..], -3..=-1), Some(&[1, 2, 3][..]));
+        assert_eq!(Clip::try_by(&a[..], -10..=-1), None);
+        assert_eq!(Clip::try_by(&a[..], ..=-1), Some(&[0, 1, 2, 3][..]));
+        assert_eq!(Clip::try_by(&a[..], ..=-10), None);
+
+        // an inclusive end is checked as its exclusive equivalent
+        assert_eq!(Clip::try_by(&a[..], ..=3), Some(&[0, 1, 2, 3][..]));
+        assert_eq!(Clip::try_by(&a[..], ..=4), None);
+        assert_eq!(Clip::try_by(&a[..], ..=-5), Some(&[][..]));
+        assert_eq!(Clip::try_by(&a[..], ..-4), Some(&[][..]));
+        assert_eq!(Clip::try_by(&a[..], ..=-6), None);
+        assert_eq!(Clip::try_by(&a[..], ..=isize::MAX), None);
+        assert_eq!(
+            Clip::try_by(&a[..], (Bound::Excluded(-5), Bound::Unbounded)),
+            Some(&[0, 1, 2, 3][..])
+        );
+        assert_eq!(
+            Clip::try_by(&a[..], (Bound::Excluded(3), Bound::Unbounded)),
+            Some(&[][..])
+        );
+        assert_eq!(
+            Clip::try_by(&a[..], (Bound::Excluded(4), Bound::Unbounded)),
+            None
+        );
+    }
+
+    #[test]
+    fn clip_strict() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::strict_by(&a, 1..-1), &[1, 2]);
+        assert_eq!(Clip::strict_by(&a, -4..4), &[0, 1, 2, 3]);
+        assert_eq!(Clip::strict_by(&a, -2..), &[2, 3]);
+        assert_eq!(Clip::strict_by(&a, ..=-1), &[0, 1, 2, 3]);
+        assert_eq!(Clip::strict_by(&a, 0..=3), &[0, 1, 2, 3]);
+        assert_eq!(Clip::strict_by(&a, ..=-5), &[]);
+        assert_eq!(Clip::strict_by(&a, ..), &[0, 1, 2, 3]);
+
+        let mut a = [0, 1, 2, 3];
+
+        Clip::strict_mut_by(&mut a, -1..)[0] = 30;
+        assert_eq!(a, [0, 1, 2, 30]);
+    }
+
+    #[test]
+    #[should_panic(expected = "clip_slice: index -10 out of range for length 4")]
+    fn clip_strict_underflow() {
+        let a = [0, 1, 2, 3];
+
+        Clip::strict_by(&a, -10..2);
+    }
+
+    #[test]
+    #[should_panic(expected = "clip_slice: index 9 out of range for length 4")]
+    fn clip_strict_overflow() {
+        let mut a = [0, 1, 2, 3];
+
+        Clip::strict_mut_by(&mut a, ..9);
+    }
+
+    #[test]
+    #[should_panic(expected = "clip_slice: index 4 out of range for length 4")]
+    fn clip_strict_inclusive_overflow() {
+        let a = [0, 1, 2, 3];
+
+        Clip::strict_by(&a, 0..=4);
+    }
+
+    #[test]
+    #[should_panic(expected = "clip_slice: range start 3 is greater than end 1 for length 4")]
+    fn clip_strict_reversed() {
+        let a = [0, 1, 2, 3];
+
+        Clip::strict_by(&a, 3..1);
+    }
+
+    #[test]
+    fn clip_get_range() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::clip_get(&a, 1..-1), Some(&[1, 2][..]));
+        assert_eq!(Clip::clip_get(&a, -2..), Some(&[2, 3][..]));
+        assert_eq!(Clip::clip_get(&a, ..-1), Some(&[0, 1, 2][..]));
+
+        // an over-large end is clamped, unlike try_by
+        assert_eq!(Clip::clip_get(&a, 1..10), Some(&[1, 2, 3][..]));
+        assert_eq!(Clip::try_by(&a, 1..10), None);
+        assert_eq!(Clip::clip_get(&a, ..10), Some(&[0, 1, 2, 3][..]));
+        assert_eq!(Clip::clip_get(&a, 10..), Some(&[][..]));
+
+        // a negative index before the front end is rejected, unlike by
+        assert_eq!(Clip::clip_get(&a, -10..2), None);
+        assert_eq!(Clip::by(&a, -10..2), &[0, 1]);
+        assert_eq!(Clip::clip_get(&a, -10..), None);
+        assert_eq!(Clip::clip_get(&a, ..-10), None);
+        assert_eq!(Clip::by(&a, ..-10), &[]);
+
+        // an inclusive end is checked as its exclusive equivalent
+        assert_eq!(Clip::clip_get(&a, ..=-5), Some(&[][..]));
+        assert_eq!(Clip::clip_get(&a, ..-4), Some(&[][..]));
+        assert_eq!(Clip::clip_get(&a, ..=-6), None);
+        assert_eq!(Clip::clip_get(&a, ..-5), None);
+        assert_eq!(
+            Clip::clip_get(&a, (Bound::Excluded(-5), Bound::Unbounded)),
+            Some(&[0, 1, 2, 3][..])
+        );
+
+        // a reversed range is rejected, even when it is reversed only after clipping
+        assert_eq!(Clip::clip_get(&a, 3..1), None);
+        assert_eq!(Clip::clip_get(&a, -1..2), None);
+        assert_eq!(Clip::clip_get(&a, 2..2), Some(&[][..]));
+
+        let mut a = [0, 1, 2, 3];
+
+        Clip::clip_get_mut(&mut a, -1..10).unwrap()[0] = 30;
+        assert_eq!(a, [0, 1, 2, 30]);
+        assert_eq!(Clip::clip_get_mut(&mut a, -5..), None);
+    }
+
+    #[test]
+    fn clip_len_of() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::len_of(4, 1..-1), 2);
+        assert_eq!(Clip::len_of(4, 3..1), 0);
+        assert_eq!(Clip::len_of(0, -1..), 0);
+
+        assert_eq!(Clip::len_of(a.len(), 1..-1), Clip::by(&a, 1..-1).len());
+        assert_eq!(Clip::len_of(a.len(), -10..10), Clip::by(&a, -10..10).len());
+        assert_eq!(Clip::len_of(a.len(), -2..), Clip::by(&a, -2..).len());
+        assert_eq!(Clip::len_of(a.len(), 9..), Clip::by(&a, 9..).len());
+        assert_eq!(Clip::len_of(a.len(), ..-1), Clip::by(&a, ..-1).len());
+        assert_eq!(Clip::len_of(a.len(), -3..=-1), Clip::by(&a, -3..=-1).len());
+        assert_eq!(Clip::len_of(a.len(), ..=0), Clip::by(&a, ..=0).len());
+        assert_eq!(Clip::len_of(a.len(), ..), Clip::by(&a, ..).len());
+    }
+
+    #[test]
+    fn clip_resolve() {
+        let a = [0, 1, 2, 3, 4, 5];
+
+        assert_eq!(Clip::resolve(6, 1..-2), (1, 4));
+        assert_eq!(Clip::resolve(6, -2..), (4, 6));
+        assert_eq!(Clip::resolve(6, ..-2), (0, 4));
+        assert_eq!(Clip::resolve(6, ..), (0, 6));
+        assert_eq!(Clip::resolve(6, -3..=-2), (3, 5));
+        assert_eq!(Clip::resolve(6, -10..10), (0, 6));
+        assert_eq!(Clip::resolve(6, 3..1), (3, 1));
+
+        let (start, end) = Clip::resolve(a.len(), 1..-2);
+        assert_eq!(&a[start..end], Clip::by(&a, 1..-2));
+        let (start, end) = Clip::resolve(a.len(), -2..);
+        assert_eq!(&a[start..end], Clip::by(&a, -2..));
+        let (start, end) = Clip::resolve(a.len(), ..=-3);
+        assert_eq!(&a[start..end], Clip::by(&a, ..=-3));
+        let (start, end) = Clip::resolve(a.len(), ..);
+        assert_eq!(&a[start..end], Clip::by(&a, ..));
+    }
+
+    #[test]
+    fn clip_str() {
+        let s = "aé中b";
+
+        assert_eq!(Clip::by_str(s, ..-1), "aé中");
+        assert_eq!(Clip::by_str(s, -2..), "中b");
+        assert_eq!(Clip::by_str(s, 1..-1), "é中");
+        assert_eq!(Clip::by_str(s, -3..=-2), "é中");
+        assert_eq!(Clip::by_str(s, ..=0), "a");
+        assert_eq!(Clip::by_str(s, -10..10), s);
+        assert_eq!(Clip::by_str(s, ..), s);
+        assert_eq!(Clip::by_str("", -1..), "");
+    }
+
     #[test]
+    #[cfg(feature = "alloc")]
     fn clip_as_slice_simple() {
         let v = vec![0, 1, 2, 3];
 
@@ -249,6 +1688,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn clip_as_slice_from() {
         let v = vec![0, 1, 2, 3];
 
@@ -262,6 +1702,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn clip_as_slice_to() {
         let v = vec![0, 1, 2, 3];
 
@@ -275,10 +1716,243 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "alloc")]
     fn clip_as_slice_full() {
         let v = vec![0, 1, 2, 3];
 
         let s = Clip::by_as_slice(&v, ..);
         assert_eq!(Vec::from(s), vec![0, 1, 2, 3]);
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_as_slice_inclusive() {
+        let v = vec![0, 1, 2, 3];
+
+        let s = Clip::by_as_slice(&v, -3..=-1);
+        assert_eq!(Vec::from(s), vec![1, 2, 3]);
+
+        let s = Clip::by_as_slice(&v, ..=-1);
+        assert_eq!(Vec::from(s), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn clip_macro() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(clip!(a, 1..-1), &[1, 2]);
+        assert_eq!(clip!(a, -2..), &[2, 3]);
+        assert_eq!(clip!(a, ..), &[0, 1, 2, 3]);
+        assert_eq!(clip!(a[-1]), &3);
+        assert_eq!(*clip!(a[0]), 0);
+
+        let mut a = [0, 1, 2, 3];
+
+        clip!(mut a, 1..-1)[0] = 10;
+        *clip!(mut a[-1]) = 30;
+        assert_eq!(a, [0, 10, 2, 30]);
+
+        let mut v = vec![0, 1, 2, 3];
+
+        assert_eq!(clip!(v, ..-1), &[0, 1, 2]);
+        assert_eq!(clip!(v[-2]), &2);
+        *clip!(mut v[-2]) = 20;
+        clip!(mut v, ..1)[0] = -1;
+        v.push(4);
+        assert_eq!(v, vec![-1, 1, 20, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "clip_slice: index out of range")]
+    fn clip_macro_out_of_range() {
+        let a = [0, 1, 2, 3];
+
+        let _ = clip!(a[-5]);
+    }
+
+    #[test]
+    fn clip_as_ref_sources() {
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::by(&a, 1..-1), &[1, 2]);
+        assert_eq!(Clip::try_by(&a, -2..), Some(&[2, 3][..]));
+
+        let v: Vec<i32> = (0..4).collect();
+
+        assert_eq!(Clip::by(&v, ..-1), &[0, 1, 2]);
+        assert_eq!(Clip::by(&v, ..), &[0, 1, 2, 3]);
+
+        let mut a = [0, 1, 2, 3];
+
+        Clip::mut_by(&mut a, -1..)[0] = 30;
+        assert_eq!(a, [0, 1, 2, 30]);
+
+        let mut v: Vec<i32> = (0..4).collect();
+
+        Clip::mut_by(&mut v, ..1)[0] = -1;
+        v.push(4);
+        assert_eq!(v, vec![-1, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn clip_lifetime_tied_to_input() {
+        fn middle<T>(v: &Vec<T>) -> &[T] {
+            Clip::by(v, 1..-1)
+        }
+
+        let v = vec![0, 1, 2, 3];
+        assert_eq!(middle(&v), &[1, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_by_cloned() {
+        let mut a = [0, 1, 2, 3];
+
+        let v = Clip::by_cloned(&a[..], -2..);
+        assert_eq!(v, vec![2, 3]);
+
+        a[3] = 30;
+        assert_eq!(v, vec![2, 3]);
+
+        assert_eq!(Clip::by_cloned(&a, 1..-1), vec![1, 2]);
+        assert_eq!(Clip::by_cloned(&a, ..-1), vec![0, 1, 2]);
+        assert_eq!(Clip::by_cloned(&a, ..), vec![0, 1, 2, 30]);
+
+        use std::string::String;
+
+        let names = [String::from("a"), String::from("b")];
+        assert_eq!(Clip::by_cloned(&names, -1..), vec![String::from("b")]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_deque() {
+        let mut d: VecDeque<i32> = VecDeque::with_capacity(4);
+        d.extend([0, 1, 2, 3]);
+        d.pop_front();
+        d.pop_front();
+        d.push_back(4);
+        d.push_back(5);
+        assert!(!d.as_slices().1.is_empty());
+
+        assert_eq!(Clip::by_deque(&mut d, -3..), &[3, 4, 5]);
+        assert_eq!(Clip::by_deque(&mut d, 1..-1), &[3, 4]);
+
+        Clip::mut_by_deque(&mut d, -3..)[0] = 30;
+        assert_eq!(d, [2, 30, 4, 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_cow() {
+        let a = [0, 1, 2, 3];
+        let borrowed: Cow<[i32]> = Cow::Borrowed(&a[..]);
+        let owned: Cow<[i32]> = Cow::Owned(vec![0, 1, 2, 3]);
+
+        assert_eq!(Clip::by_cow(&borrowed, 1..-1), &[1, 2]);
+        assert_eq!(Clip::by_cow(&owned, 1..-1), &[1, 2]);
+        assert_eq!(Clip::by_cow(&borrowed, -2..), Clip::by_cow(&owned, -2..));
+        assert_eq!(Clip::by_cow(&borrowed, ..=-2), Clip::by_cow(&owned, ..=-2));
+        assert_eq!(Clip::by_cow(&borrowed, ..), Clip::by_cow(&owned, ..));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn clip_cow_mut() {
+        let a = [0, 1, 2, 3];
+        let mut borrowed: Cow<[i32]> = Cow::Borrowed(&a[..]);
+
+        Clip::by_cow_mut(&mut borrowed, -1..)[0] = 30;
+        assert!(matches!(borrowed, Cow::Owned(_)));
+        assert_eq!(&*borrowed, &[0, 1, 2, 30]);
+        assert_eq!(a, [0, 1, 2, 3]);
+
+        let mut owned: Cow<[i32]> = Cow::Owned(vec![0, 1, 2, 3]);
+        let ptr = owned.as_ptr();
+
+        Clip::by_cow_mut(&mut owned, -1..)[0] = 30;
+        assert_eq!(owned.as_ptr(), ptr);
+        assert_eq!(&*owned, &[0, 1, 2, 30]);
+    }
+
+    #[test]
+    #[cfg(feature = "trace")]
+    fn clip_trace() {
+        use std::string::{String, ToString};
+        use std::sync::Mutex;
+
+        static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        struct Recorder;
+
+        impl log::Log for Recorder {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+            fn log(&self, record: &log::Record) {
+                MESSAGES.lock().unwrap().push(record.args().to_string());
+            }
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&Recorder).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+
+        let a = [0, 1, 2, 3];
+
+        assert_eq!(Clip::by(&a, -10..-1), &[0, 1, 2]);
+        assert_eq!(Clip::by(&a, 1..9), &[1, 2, 3]);
+
+        let b = [0, 1, 2, 3, 4, 5, 6];
+
+        assert_eq!(Clip::by(&b, ..=-10), &[]);
+        assert_eq!(Clip::by(&b, ..=7), &b);
+        assert_eq!(Clip::by(&b, ..=6), &b);
+        assert_eq!(Clip::clip_get(&b, 5..11), Some(&[5, 6][..]));
+        assert_eq!(Clip::first_n(&b, 10), &b);
+
+        let messages = MESSAGES.lock().unwrap();
+        assert!(messages
+            .iter()
+            .any(|m| m == "clip: pos=-10 len=4 -> 0 (underflow)"));
+        assert!(messages
+            .iter()
+            .any(|m| m == "clip: pos=9 len=4 -> 4 (overflow)"));
+        assert!(!messages.iter().any(|m| m.starts_with("clip: pos=-1 len=4")));
+        assert!(messages
+            .iter()
+            .any(|m| m == "clip: pos=-10 len=7 -> 0 (underflow)"));
+        assert!(messages
+            .iter()
+            .any(|m| m == "clip: pos=7 len=7 -> 7 (overflow)"));
+        assert!(!messages.iter().any(|m| m.starts_with("clip: pos=6 len=7")));
+        assert!(messages
+            .iter()
+            .any(|m| m == "clip: pos=11 len=7 -> 7 (overflow)"));
+        assert!(messages
+            .iter()
+            .any(|m| m == "clip: n=10 len=7 -> 7 (overflow)"));
+    }
+
+    #[test]
+    fn clip_array_refs() {
+        assert_eq!(Clip::by(&[0, 1, 2, 3], 1..-1), &[1, 2]);
+        assert_eq!(Clip::by(&[0, 1, 2, 3], -2..), &[2, 3]);
+        assert_eq!(Clip::by(&[0, 1, 2, 3], ..-1), &[0, 1, 2]);
+        assert_eq!(Clip::by(&[0, 1, 2, 3], ..), &[0, 1, 2, 3]);
+
+        assert_eq!(Clip::mut_by(&mut [0, 1, 2, 3], 1..-1), &mut [1, 2]);
+        assert_eq!(Clip::mut_by(&mut [0, 1, 2, 3], -2..), &mut [2, 3]);
+        assert_eq!(Clip::mut_by(&mut [0, 1, 2, 3], ..-1), &mut [0, 1, 2]);
+        assert_eq!(Clip::mut_by(&mut [0, 1, 2, 3], ..), &mut [0, 1, 2, 3]);
+
+        let mut a = [0, 1, 2, 3];
+
+        Clip::mut_by(&mut a, 1..-1)[0] = 10;
+        Clip::mut_by(&mut a, -1..)[0] = 30;
+        Clip::mut_by(&mut a, ..1)[0] = -1;
+        Clip::mut_by(&mut a, ..)[2] = 20;
+        assert_eq!(a, [-1, 10, 20, 30]);
+    }
 }